@@ -1,4 +1,7 @@
-use anyhow::Result;
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+use anyhow::{Context, Result};
 
 pub trait ClipboardInserter: Send {
     fn insert_text(&self, text: &str) -> Result<()>;
@@ -14,3 +17,183 @@ impl ClipboardInserter for MacOsClipboardInserter {
         Ok(())
     }
 }
+
+/// A copy command and its arguments, e.g. `CommandConfig { prg: "pbcopy", args: &[] }`.
+///
+/// Modeled on the clipboard provider pattern used by neovim/helix: the
+/// transcript is piped to the command's stdin rather than passed through a
+/// native clipboard API, so this works anywhere the binary is on `PATH`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CommandConfig {
+    pub prg: &'static str,
+    pub args: &'static [&'static str],
+}
+
+/// Shells out to an external copy command, piping the transcript to its
+/// stdin. Holds an ordered list of candidates so a command that isn't
+/// installed (e.g. `wl-copy` missing on a Wayland session that only ships
+/// `xclip`) can fall back to the next one at call time.
+pub struct CommandClipboardInserter {
+    candidates: Vec<CommandConfig>,
+}
+
+impl CommandClipboardInserter {
+    pub fn new(config: CommandConfig) -> Self {
+        Self::with_fallbacks(vec![config])
+    }
+
+    pub fn with_fallbacks(candidates: Vec<CommandConfig>) -> Self {
+        Self { candidates }
+    }
+
+    /// `wl-copy` under Wayland, falling back to `xclip -selection clipboard`
+    /// if `wl-copy` isn't actually installed.
+    pub fn linux() -> Self {
+        let wl_copy = CommandConfig {
+            prg: "wl-copy",
+            args: &[],
+        };
+        let xclip = CommandConfig {
+            prg: "xclip",
+            args: &["-selection", "clipboard"],
+        };
+
+        if std::env::var_os("WAYLAND_DISPLAY").is_some() {
+            Self::with_fallbacks(vec![wl_copy, xclip])
+        } else {
+            Self::new(xclip)
+        }
+    }
+
+    pub fn macos() -> Self {
+        Self::new(CommandConfig {
+            prg: "pbcopy",
+            args: &[],
+        })
+    }
+
+    /// `clip.exe`, reachable both on native Windows and from WSL.
+    pub fn windows() -> Self {
+        Self::new(CommandConfig {
+            prg: "clip.exe",
+            args: &[],
+        })
+    }
+}
+
+impl ClipboardInserter for CommandClipboardInserter {
+    fn insert_text(&self, text: &str) -> Result<()> {
+        anyhow::ensure!(
+            !self.candidates.is_empty(),
+            "no clipboard command configured"
+        );
+
+        let mut spawn_err = None;
+        for config in &self.candidates {
+            let mut child = match Command::new(config.prg)
+                .args(config.args)
+                .stdin(Stdio::piped())
+                .spawn()
+            {
+                Ok(child) => child,
+                Err(err) => {
+                    // Not installed (or otherwise unlaunchable) — try the
+                    // next candidate rather than failing outright.
+                    spawn_err = Some(err);
+                    continue;
+                }
+            };
+
+            child
+                .stdin
+                .take()
+                .context("clipboard command stdin was not piped")?
+                .write_all(text.as_bytes())
+                .with_context(|| format!("failed writing to `{}` stdin", config.prg))?;
+
+            let status = child
+                .wait()
+                .with_context(|| format!("failed waiting on `{}`", config.prg))?;
+            anyhow::ensure!(status.success(), "`{}` exited with {status}", config.prg);
+            return Ok(());
+        }
+
+        Err(spawn_err.expect("non-empty candidates always attempt at least one spawn"))
+            .with_context(|| "failed to launch any configured clipboard command")
+    }
+}
+
+/// Picks the clipboard backend for the current platform. Windows, and WSL
+/// (detected via `WSL_DISTRO_NAME`), use `clip.exe`. Other Linux prefers
+/// Wayland's `wl-copy` when `WAYLAND_DISPLAY` is set, otherwise falls back to
+/// `xclip`. Call this instead of hardcoding `MacOsClipboardInserter` so the
+/// app runs on Linux and Windows/WSL without native API bindings.
+pub fn detect() -> Box<dyn ClipboardInserter> {
+    if cfg!(target_os = "macos") {
+        Box::new(CommandClipboardInserter::macos())
+    } else if cfg!(target_os = "windows") || std::env::var_os("WSL_DISTRO_NAME").is_some() {
+        Box::new(CommandClipboardInserter::windows())
+    } else {
+        Box::new(CommandClipboardInserter::linux())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn linux_prefers_wayland_with_xclip_fallback() {
+        // SAFETY: this test owns WAYLAND_DISPLAY start to finish and runs
+        // both branches itself so no other test can interleave a conflicting
+        // value for the process-global env var.
+        let previous = std::env::var_os("WAYLAND_DISPLAY");
+
+        std::env::set_var("WAYLAND_DISPLAY", "wayland-0");
+        assert_eq!(
+            CommandClipboardInserter::linux().candidates,
+            vec![
+                CommandConfig {
+                    prg: "wl-copy",
+                    args: &[]
+                },
+                CommandConfig {
+                    prg: "xclip",
+                    args: &["-selection", "clipboard"]
+                },
+            ]
+        );
+
+        std::env::remove_var("WAYLAND_DISPLAY");
+        assert_eq!(
+            CommandClipboardInserter::linux().candidates,
+            vec![CommandConfig {
+                prg: "xclip",
+                args: &["-selection", "clipboard"]
+            }]
+        );
+
+        match previous {
+            Some(value) => std::env::set_var("WAYLAND_DISPLAY", value),
+            None => std::env::remove_var("WAYLAND_DISPLAY"),
+        }
+    }
+
+    #[test]
+    fn falls_back_to_next_candidate_when_a_command_is_missing() {
+        let inserter = CommandClipboardInserter::with_fallbacks(vec![
+            CommandConfig {
+                prg: "definitely-not-a-real-clipboard-binary",
+                args: &[],
+            },
+            CommandConfig {
+                prg: "cat",
+                args: &["/dev/null"],
+            },
+        ]);
+
+        inserter
+            .insert_text("hello")
+            .expect("should fall back to the working candidate");
+    }
+}
@@ -11,11 +11,24 @@ pub trait SttProvider: Send {
     fn begin_session(&mut self) -> Result<()>;
     fn feed_audio_chunk(&mut self, _pcm: &[f32]) -> Result<()>;
     fn end_session(&mut self) -> Result<ProviderResult>;
+
+    /// Returns a partial (`is_partial: true`) result if enough new audio has
+    /// arrived since the last call to produce one, or `None` if nothing has
+    /// changed yet. Called repeatedly by the state machine's capture loop
+    /// while `UiState::Recording`.
+    fn poll_partial(&mut self) -> Result<Option<ProviderResult>>;
 }
 
+/// One simulated word is "recognized" per `FRAMES_PER_WORD` frames of fed
+/// audio, so `poll_partial` has something incremental to emit without a real
+/// model in the loop.
+const SIMULATED_WORDS: &[&str] = &["this", "is", "a", "simulated", "transcript"];
+const FRAMES_PER_WORD: usize = 1600;
+
 #[derive(Default)]
 pub struct PlaceholderProvider {
     buffered_frames: usize,
+    emitted_words: usize,
 }
 
 impl SttProvider for PlaceholderProvider {
@@ -25,6 +38,7 @@ impl SttProvider for PlaceholderProvider {
 
     fn begin_session(&mut self) -> Result<()> {
         self.buffered_frames = 0;
+        self.emitted_words = 0;
         Ok(())
     }
 
@@ -42,4 +56,43 @@ impl SttProvider for PlaceholderProvider {
             is_partial: false,
         })
     }
+
+    fn poll_partial(&mut self) -> Result<Option<ProviderResult>> {
+        let available_words = (self.buffered_frames / FRAMES_PER_WORD).min(SIMULATED_WORDS.len());
+        if available_words <= self.emitted_words {
+            return Ok(None);
+        }
+
+        self.emitted_words = available_words;
+        Ok(Some(ProviderResult {
+            text: SIMULATED_WORDS[..available_words].join(" "),
+            is_partial: true,
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn emits_incremental_partials_as_frames_arrive() {
+        let mut provider = PlaceholderProvider::default();
+        provider.begin_session().unwrap();
+
+        assert_eq!(provider.poll_partial().unwrap(), None);
+
+        provider.feed_audio_chunk(&vec![0.0; FRAMES_PER_WORD]).unwrap();
+        let first = provider.poll_partial().unwrap().expect("first partial");
+        assert_eq!(first.text, "this");
+        assert!(first.is_partial);
+
+        assert_eq!(provider.poll_partial().unwrap(), None);
+
+        provider
+            .feed_audio_chunk(&vec![0.0; FRAMES_PER_WORD * 2])
+            .unwrap();
+        let second = provider.poll_partial().unwrap().expect("second partial");
+        assert_eq!(second.text, "this is a");
+    }
 }
@@ -5,6 +5,14 @@ pub struct AppSettings {
     pub global_hotkey: String,
     pub provider: String,
     pub auto_insert: bool,
+    pub vad_enabled: bool,
+    pub vad_ratio: f32,
+    pub vad_flux_threshold: f32,
+    pub vad_trailing_silence_ms: u32,
+    pub speak_confirmation: bool,
+    pub auto_punctuate: bool,
+    pub spoken_commands: bool,
+    pub proper_nouns: Vec<String>,
 }
 
 impl Default for AppSettings {
@@ -13,6 +21,14 @@ impl Default for AppSettings {
             global_hotkey: "Option+Space".to_string(),
             provider: "placeholder".to_string(),
             auto_insert: true,
+            vad_enabled: false,
+            vad_ratio: 2.5,
+            vad_flux_threshold: 50.0,
+            vad_trailing_silence_ms: 800,
+            speak_confirmation: false,
+            auto_punctuate: true,
+            spoken_commands: true,
+            proper_nouns: Vec::new(),
         }
     }
 }
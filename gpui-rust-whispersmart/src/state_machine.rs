@@ -5,14 +5,25 @@ use crate::{
     model::{DictationSession, UiState},
     provider::SttProvider,
     services::AudioCaptureService,
+    settings::AppSettings,
+    transcript::PostProcessor,
+    tts::TtsService,
+    vad::{VadDetector, VadEvent},
 };
 
+type Observer = Box<dyn FnMut(&UiState, &DictationSession) + Send>;
+
 pub struct DictationStateMachine {
     pub state: UiState,
     pub session: DictationSession,
     provider: Box<dyn SttProvider>,
     audio: Box<dyn AudioCaptureService>,
     clipboard: Box<dyn ClipboardInserter>,
+    vad: Option<VadDetector>,
+    tts: Option<Box<dyn TtsService>>,
+    speak_confirmation: bool,
+    observer: Option<Observer>,
+    post_processor: PostProcessor,
 }
 
 impl DictationStateMachine {
@@ -27,42 +38,227 @@ impl DictationStateMachine {
             provider,
             audio,
             clipboard,
+            vad: None,
+            tts: None,
+            speak_confirmation: false,
+            observer: None,
+            post_processor: PostProcessor::new(true, true, Vec::new()),
+        }
+    }
+
+    pub fn apply_transcript_settings(&mut self, settings: &AppSettings) {
+        self.post_processor = PostProcessor::new(
+            settings.auto_punctuate,
+            settings.spoken_commands,
+            settings.proper_nouns.clone(),
+        );
+    }
+
+    pub fn set_observer(&mut self, observer: Option<Observer>) {
+        self.observer = observer;
+    }
+
+    fn notify(&mut self) {
+        if let Some(observer) = &mut self.observer {
+            observer(&self.state, &self.session);
         }
     }
 
+    pub fn apply_vad_settings(&mut self, settings: &AppSettings) {
+        self.vad = settings.vad_enabled.then(|| {
+            VadDetector::new(
+                settings.vad_ratio,
+                settings.vad_flux_threshold,
+                VadDetector::hangover_frames_for_ms(settings.vad_trailing_silence_ms),
+            )
+        });
+    }
+
+    pub fn apply_tts_settings(&mut self, settings: &AppSettings, tts: Option<Box<dyn TtsService>>) {
+        self.speak_confirmation = settings.speak_confirmation;
+        self.tts = tts;
+    }
+
     pub fn start_recording(&mut self) -> Result<()> {
         self.provider.begin_session()?;
         self.audio.start_capture()?;
         self.session = DictationSession::default();
         self.state = UiState::Recording;
+        self.notify();
         Ok(())
     }
 
-    pub fn stop_and_transcribe(&mut self) -> Result<()> {
-        self.state = UiState::Transcribing;
+    /// Called on a polling cadence by the UI's capture loop while
+    /// `Recording`: reads one chunk, feeds it to the provider and (when
+    /// enabled) the VAD, and folds any partial result into
+    /// `session.partial_text` for the observer to render.
+    pub fn poll_recording(&mut self) -> Result<()> {
+        if self.state != UiState::Recording {
+            return Ok(());
+        }
+
         let chunk = self.audio.read_mono_chunk()?;
         self.provider.feed_audio_chunk(&chunk)?;
+
+        if let Some(partial) = self.provider.poll_partial()? {
+            self.session.partial_text = partial.text;
+            self.notify();
+        }
+
+        if let Some(vad) = &mut self.vad {
+            if vad.push(&chunk) == VadEvent::EndOfSpeech {
+                // The chunk that triggered EndOfSpeech was already read and
+                // fed above, so finish the session without draining another.
+                return self.finish_session(false);
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn stop_and_transcribe(&mut self) -> Result<()> {
+        self.finish_session(true)
+    }
+
+    /// `drain` controls whether one more chunk is read and fed before ending
+    /// the provider session. The hotkey-driven stop needs it to capture
+    /// audio spoken between the last poll and the stop signal; a VAD-driven
+    /// stop doesn't, since `poll_recording` already fed the chunk that
+    /// decided speech had ended.
+    fn finish_session(&mut self, drain: bool) -> Result<()> {
+        self.state = UiState::Transcribing;
+        self.notify();
+
+        if drain {
+            let chunk = self.audio.read_mono_chunk()?;
+            self.provider.feed_audio_chunk(&chunk)?;
+        }
         self.audio.stop_capture()?;
 
         let result = self.provider.end_session()?;
-        self.session.final_text = Some(result.text.clone());
+        let text = self.post_processor.process(&result.text);
+        self.session.final_text = Some(text.clone());
 
-        if !result.text.trim().is_empty() {
-            self.clipboard.insert_text(&result.text)?;
+        if !text.trim().is_empty() {
+            self.clipboard.insert_text(&text)?;
             self.state = UiState::Success;
+
+            if self.speak_confirmation {
+                if let Some(tts) = &mut self.tts {
+                    tts.speak(&text)?;
+                }
+            }
         } else {
             self.state = UiState::Error("No transcript returned".to_string());
         }
 
+        self.notify();
         Ok(())
     }
 
     pub fn reset_to_idle(&mut self) {
         self.state = UiState::Idle;
         self.session.partial_text.clear();
+        self.notify();
     }
 
     pub fn fail(&mut self, reason: impl Into<String>) {
         self.state = UiState::Error(reason.into());
+        self.notify();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use super::*;
+    use crate::{provider::PlaceholderProvider, services::StubAudioCaptureService};
+
+    #[derive(Default)]
+    struct RecordingTtsService {
+        calls: Arc<Mutex<Vec<String>>>,
+    }
+
+    impl TtsService for RecordingTtsService {
+        fn speak(&mut self, text: &str) -> Result<()> {
+            self.calls.lock().unwrap().push(text.to_string());
+            Ok(())
+        }
+
+        fn stop(&mut self) -> Result<()> {
+            Ok(())
+        }
+    }
+
+    #[derive(Default)]
+    struct NoopClipboardInserter;
+
+    impl ClipboardInserter for NoopClipboardInserter {
+        fn insert_text(&self, _text: &str) -> Result<()> {
+            Ok(())
+        }
+    }
+
+    fn machine() -> DictationStateMachine {
+        DictationStateMachine::new(
+            Box::new(PlaceholderProvider::default()),
+            Box::new(StubAudioCaptureService),
+            Box::new(NoopClipboardInserter),
+        )
+    }
+
+    #[test]
+    fn speaks_confirmation_when_enabled_and_installed() {
+        let calls = Arc::new(Mutex::new(Vec::new()));
+        let mut machine = machine();
+        let settings = AppSettings {
+            speak_confirmation: true,
+            ..Default::default()
+        };
+        machine.apply_tts_settings(
+            &settings,
+            Some(Box::new(RecordingTtsService {
+                calls: calls.clone(),
+            })),
+        );
+
+        machine.start_recording().unwrap();
+        machine.stop_and_transcribe().unwrap();
+
+        assert_eq!(calls.lock().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn does_not_speak_when_confirmation_disabled() {
+        let calls = Arc::new(Mutex::new(Vec::new()));
+        let mut machine = machine();
+        let settings = AppSettings::default();
+        machine.apply_tts_settings(
+            &settings,
+            Some(Box::new(RecordingTtsService {
+                calls: calls.clone(),
+            })),
+        );
+
+        machine.start_recording().unwrap();
+        machine.stop_and_transcribe().unwrap();
+
+        assert!(calls.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn does_not_speak_when_no_backend_installed() {
+        let mut machine = machine();
+        let settings = AppSettings {
+            speak_confirmation: true,
+            ..Default::default()
+        };
+        machine.apply_tts_settings(&settings, None);
+
+        machine.start_recording().unwrap();
+        // Only assert this doesn't panic without a backend installed; there
+        // is no recorder to inspect since no `TtsService` was ever given.
+        machine.stop_and_transcribe().unwrap();
     }
 }
@@ -0,0 +1,235 @@
+/// Rewrites a spoken command literal (case-insensitive) into its punctuation.
+/// The third field controls whether the space *after* the match is also
+/// trimmed: punctuation like `,`/`.`/`?` attaches to the preceding word but
+/// keeps the following space, while a line break has no business with
+/// flanking spaces on either side.
+const SPOKEN_COMMANDS: &[(&str, &str, bool)] = &[
+    ("new line", "\n", true),
+    ("comma", ",", false),
+    ("period", ".", false),
+    ("question mark", "?", false),
+];
+
+/// Cleans up raw STT output before it is inserted: capitalizes sentence
+/// starts, capitalizes configured proper nouns, collapses doubled spaces,
+/// and (optionally) rewrites spoken punctuation commands like "comma" into
+/// `,`. Built from `AppSettings`: `spoken_commands` toggles the rewrite pass,
+/// and `auto_punctuate` toggles the space, sentence and proper-noun passes
+/// together.
+pub struct PostProcessor {
+    auto_punctuate: bool,
+    spoken_commands: bool,
+    proper_nouns: Vec<String>,
+}
+
+impl PostProcessor {
+    pub fn new(auto_punctuate: bool, spoken_commands: bool, proper_nouns: Vec<String>) -> Self {
+        Self {
+            auto_punctuate,
+            spoken_commands,
+            proper_nouns,
+        }
+    }
+
+    pub fn process(&self, raw: &str) -> String {
+        let mut text = raw.to_string();
+
+        if self.spoken_commands {
+            text = rewrite_spoken_commands(&text);
+        }
+
+        if self.auto_punctuate {
+            text = collapse_spaces(&text);
+            text = capitalize_sentences(&text);
+            text = capitalize_proper_nouns(&text, &self.proper_nouns);
+        }
+
+        text
+    }
+}
+
+fn rewrite_spoken_commands(text: &str) -> String {
+    let mut result = text.to_string();
+    for (literal, replacement, trim_trailing_space) in SPOKEN_COMMANDS {
+        result = replace_ignore_case(&result, literal, replacement, *trim_trailing_space);
+    }
+    result
+}
+
+/// Replaces `literal` with `replacement`, case-insensitively, but only where
+/// `literal` is a whole word — flanked by a string boundary or a
+/// non-alphabetic character on both sides — so e.g. matching "comma" doesn't
+/// also mangle "commander".
+fn replace_ignore_case(
+    text: &str,
+    literal: &str,
+    replacement: &str,
+    trim_trailing_space: bool,
+) -> String {
+    let lower = text.to_lowercase();
+    let literal_lower = literal.to_lowercase();
+    let mut result = String::with_capacity(text.len());
+    let mut cursor = 0;
+
+    while let Some(rel_pos) = lower[cursor..].find(&literal_lower) {
+        let pos = cursor + rel_pos;
+        let match_end = pos + literal.len();
+
+        let preceded_by_boundary = text[..pos]
+            .chars()
+            .next_back()
+            .map_or(true, |c| !c.is_alphabetic());
+        let followed_by_boundary = text[match_end..]
+            .chars()
+            .next()
+            .map_or(true, |c| !c.is_alphabetic());
+
+        if !preceded_by_boundary || !followed_by_boundary {
+            // Not a whole-word match (e.g. "period" inside "periodically");
+            // leave it untouched and resume searching just past it.
+            let skip_len = text[pos..].chars().next().map_or(1, char::len_utf8);
+            result.push_str(&text[cursor..pos + skip_len]);
+            cursor = pos + skip_len;
+            continue;
+        }
+
+        // The literal replaces a word token, so attach it to the preceding
+        // word by dropping the space that separated them.
+        result.push_str(text[cursor..pos].trim_end_matches(' '));
+        result.push_str(replacement);
+
+        let mut after = match_end;
+        if trim_trailing_space {
+            while text[after..].starts_with(' ') {
+                after += 1;
+            }
+        }
+        cursor = after;
+    }
+    result.push_str(&text[cursor..]);
+    result
+}
+
+fn collapse_spaces(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut last_was_space = false;
+    for ch in text.chars() {
+        if ch == ' ' {
+            if !last_was_space {
+                result.push(ch);
+            }
+            last_was_space = true;
+        } else {
+            result.push(ch);
+            last_was_space = false;
+        }
+    }
+    result
+}
+
+fn capitalize_sentences(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut capitalize_next = true;
+
+    for ch in text.chars() {
+        if capitalize_next && ch.is_alphabetic() {
+            result.extend(ch.to_uppercase());
+            capitalize_next = false;
+        } else {
+            result.push(ch);
+        }
+
+        if matches!(ch, '.' | '?' | '!') {
+            capitalize_next = true;
+        } else if !ch.is_whitespace() {
+            capitalize_next = false;
+        }
+    }
+
+    result
+}
+
+/// Matches each whitespace-separated token against the proper-noun list on
+/// its alphabetic core, ignoring any punctuation attached to it (e.g. the
+/// trailing `.` in a sentence-final "whisper."), and reinserts that
+/// punctuation around the corrected casing.
+fn capitalize_proper_nouns(text: &str, proper_nouns: &[String]) -> String {
+    text.split_inclusive(char::is_whitespace)
+        .map(|word| {
+            let start = word.find(|c: char| c.is_alphabetic());
+            let end = word.rfind(|c: char| c.is_alphabetic());
+            let (Some(start), Some(end)) = (start, end) else {
+                return word.to_string();
+            };
+            let core = &word[start..=end];
+
+            match proper_nouns.iter().find(|noun| core.eq_ignore_ascii_case(noun)) {
+                Some(noun) => format!("{}{}{}", &word[..start], noun, &word[end + 1..]),
+                None => word.to_string(),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn capitalizes_sentence_starts() {
+        let processor = PostProcessor::new(true, false, vec![]);
+        assert_eq!(
+            processor.process("hello world. how are you? fine!"),
+            "Hello world. How are you? Fine!"
+        );
+    }
+
+    #[test]
+    fn capitalizes_configured_proper_nouns() {
+        let processor = PostProcessor::new(true, false, vec!["Whisper".to_string()]);
+        assert_eq!(processor.process("using whisper daily"), "Using Whisper daily");
+    }
+
+    #[test]
+    fn capitalizes_proper_nouns_with_attached_punctuation() {
+        let processor = PostProcessor::new(true, false, vec!["Whisper".to_string()]);
+        assert_eq!(
+            processor.process("I am using whisper. it helps"),
+            "I am using Whisper. It helps"
+        );
+        assert_eq!(processor.process("ask whisper, please"), "Ask Whisper, please");
+    }
+
+    #[test]
+    fn collapses_doubled_spaces() {
+        let processor = PostProcessor::new(true, false, vec![]);
+        assert_eq!(processor.process("too   many  spaces"), "Too many spaces");
+    }
+
+    #[test]
+    fn rewrites_spoken_commands() {
+        let processor = PostProcessor::new(false, true, vec![]);
+        assert_eq!(
+            processor.process("dear team comma new line see you soon period"),
+            "dear team,\nsee you soon."
+        );
+    }
+
+    #[test]
+    fn spoken_commands_then_punctuation_pass_compose() {
+        let processor = PostProcessor::new(true, true, vec![]);
+        assert_eq!(
+            processor.process("hello comma world period nice"),
+            "Hello, world. Nice"
+        );
+    }
+
+    #[test]
+    fn spoken_commands_only_match_whole_words() {
+        let processor = PostProcessor::new(false, true, vec![]);
+        assert_eq!(
+            processor.process("I will check periodically and commander approved"),
+            "I will check periodically and commander approved"
+        );
+    }
+}
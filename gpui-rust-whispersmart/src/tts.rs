@@ -0,0 +1,45 @@
+use anyhow::Result;
+use tts::Tts;
+
+pub trait TtsService: Send {
+    fn speak(&mut self, text: &str) -> Result<()>;
+    fn stop(&mut self) -> Result<()>;
+}
+
+#[derive(Default)]
+pub struct StubTtsService;
+
+impl TtsService for StubTtsService {
+    fn speak(&mut self, text: &str) -> Result<()> {
+        println!("[tts stub] Would speak: {text}");
+        Ok(())
+    }
+
+    fn stop(&mut self) -> Result<()> {
+        Ok(())
+    }
+}
+
+/// Wraps the cross-platform `tts` crate (SAPI/WinRT on Windows,
+/// AVSpeechSynthesizer on macOS, Speech Dispatcher on Linux).
+pub struct SystemTtsService {
+    tts: Tts,
+}
+
+impl SystemTtsService {
+    pub fn new() -> Result<Self> {
+        Ok(Self { tts: Tts::default()? })
+    }
+}
+
+impl TtsService for SystemTtsService {
+    fn speak(&mut self, text: &str) -> Result<()> {
+        self.tts.speak(text, true)?;
+        Ok(())
+    }
+
+    fn stop(&mut self) -> Result<()> {
+        self.tts.stop()?;
+        Ok(())
+    }
+}
@@ -0,0 +1,217 @@
+use realfft::RealFftPlanner;
+
+const FRAME_LEN: usize = 512;
+const HOP_LEN: usize = 160;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VadEvent {
+    Speech,
+    Silence,
+    EndOfSpeech,
+}
+
+/// Energy + spectral-flux voice activity detector. Frames are accumulated
+/// from successive `push` calls into a rolling Hann-windowed 512-sample
+/// buffer advanced in 160-sample hops (16 kHz, ~10 ms hop), classified as
+/// speech when short-time RMS energy exceeds an adaptive noise floor or
+/// spectral flux exceeds `flux_threshold`. `EndOfSpeech` fires once speech
+/// has stayed below both gates for `hangover_frames` in a row.
+pub struct VadDetector {
+    ratio: f32,
+    flux_threshold: f32,
+    hangover_frames: u32,
+    window: Vec<f32>,
+    planner: RealFftPlanner<f32>,
+    carry: Vec<f32>,
+    prev_magnitudes: Option<Vec<f32>>,
+    noise_floor: f32,
+    silence_streak: u32,
+    speaking: bool,
+}
+
+impl VadDetector {
+    /// `flux_threshold` is on the raw-magnitude-sum scale `spectral_flux`
+    /// produces (tens to hundreds for a 512-point FFT), not the same scale as
+    /// `ratio` (a small multiplier on the noise floor) — keep them separate
+    /// or flux ends up comparing against a threshold sized for energy and
+    /// fires on ordinary frame-to-frame noise.
+    pub fn new(ratio: f32, flux_threshold: f32, hangover_frames: u32) -> Self {
+        let window = hann_window(FRAME_LEN);
+        Self {
+            ratio,
+            flux_threshold,
+            hangover_frames,
+            window,
+            planner: RealFftPlanner::<f32>::new(),
+            carry: Vec::with_capacity(FRAME_LEN),
+            prev_magnitudes: None,
+            noise_floor: 1e-4,
+            silence_streak: 0,
+            speaking: false,
+        }
+    }
+
+    /// Converts a trailing-silence window into a hangover frame count at a
+    /// 160-sample hop (10 ms at 16 kHz), e.g. 800 ms -> 80 frames.
+    pub fn hangover_frames_for_ms(trailing_silence_ms: u32) -> u32 {
+        let hop_ms = (HOP_LEN as f32 / 16_000.0) * 1000.0;
+        ((trailing_silence_ms as f32) / hop_ms).round().max(1.0) as u32
+    }
+
+    pub fn push(&mut self, pcm: &[f32]) -> VadEvent {
+        self.carry.extend_from_slice(pcm);
+
+        let mut last_event = if self.speaking {
+            VadEvent::Speech
+        } else {
+            VadEvent::Silence
+        };
+
+        while self.carry.len() >= FRAME_LEN {
+            let frame: Vec<f32> = self.carry[..FRAME_LEN].to_vec();
+            self.carry.drain(..HOP_LEN.min(self.carry.len()));
+            last_event = self.process_frame(&frame);
+        }
+
+        last_event
+    }
+
+    fn process_frame(&mut self, frame: &[f32]) -> VadEvent {
+        let energy = rms_energy(frame);
+        let flux = self.spectral_flux(frame);
+
+        // Re-evaluated every frame, even while already `speaking` — otherwise
+        // a drop in energy can never reach the `else` branch below and
+        // `silence_streak`/`EndOfSpeech` become unreachable.
+        let is_active = energy > self.noise_floor * self.ratio || flux > self.flux_threshold;
+
+        if is_active {
+            self.silence_streak = 0;
+            self.speaking = true;
+            VadEvent::Speech
+        } else {
+            // Noise floor adapts on every frame below both gates, including
+            // hangover frames counted while still `speaking`.
+            const FLOOR_DECAY: f32 = 0.95;
+            self.noise_floor = self.noise_floor * FLOOR_DECAY + energy * (1.0 - FLOOR_DECAY);
+
+            if self.speaking {
+                self.silence_streak += 1;
+                if self.silence_streak >= self.hangover_frames {
+                    self.speaking = false;
+                    self.silence_streak = 0;
+                    VadEvent::EndOfSpeech
+                } else {
+                    VadEvent::Speech
+                }
+            } else {
+                VadEvent::Silence
+            }
+        }
+    }
+
+    fn spectral_flux(&mut self, frame: &[f32]) -> f32 {
+        let mut windowed: Vec<f32> = frame
+            .iter()
+            .zip(self.window.iter())
+            .map(|(x, w)| x * w)
+            .collect();
+
+        let fft = self.planner.plan_fft_forward(FRAME_LEN);
+        let mut spectrum = fft.make_output_vec();
+        if fft.process(&mut windowed, &mut spectrum).is_err() {
+            return 0.0;
+        }
+
+        let magnitudes: Vec<f32> = spectrum.iter().map(|c| c.norm()).collect();
+
+        let flux = match &self.prev_magnitudes {
+            Some(prev) => magnitudes
+                .iter()
+                .zip(prev.iter())
+                .map(|(cur, prev)| (cur - prev).max(0.0))
+                .sum(),
+            None => 0.0,
+        };
+
+        self.prev_magnitudes = Some(magnitudes);
+        flux
+    }
+}
+
+fn rms_energy(frame: &[f32]) -> f32 {
+    let sum_sq: f32 = frame.iter().map(|x| x * x).sum();
+    (sum_sq / frame.len() as f32).sqrt()
+}
+
+fn hann_window(len: usize) -> Vec<f32> {
+    (0..len)
+        .map(|n| {
+            0.5 * (1.0 - (2.0 * std::f32::consts::PI * n as f32 / (len as f32 - 1.0)).cos())
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn silence_stays_silent() {
+        let mut vad = VadDetector::new(4.0, 50.0, 5);
+        let quiet = vec![0.0_f32; HOP_LEN];
+        for _ in 0..10 {
+            assert_eq!(vad.push(&quiet), VadEvent::Silence);
+        }
+    }
+
+    #[test]
+    fn loud_burst_then_hangover_emits_end_of_speech() {
+        let mut vad = VadDetector::new(2.0, 50.0, 3);
+        let quiet = vec![0.0_f32; HOP_LEN];
+        for _ in 0..5 {
+            vad.push(&quiet);
+        }
+
+        let loud = vec![0.8_f32; HOP_LEN];
+        let event = vad.push(&loud);
+        assert_eq!(event, VadEvent::Speech);
+
+        let mut saw_end = false;
+        for _ in 0..8 {
+            if vad.push(&quiet) == VadEvent::EndOfSpeech {
+                saw_end = true;
+                break;
+            }
+        }
+        assert!(saw_end, "expected EndOfSpeech after trailing silence");
+    }
+
+    #[test]
+    fn background_noise_does_not_falsely_trigger_speech() {
+        // Two frames at the same, very low RMS energy (well under the
+        // initial noise floor's gate) but with different spectral shape
+        // (alternating +/- vs -/+), so every transition produces nonzero
+        // flux without ever being true silence. A flux threshold sized for
+        // actual speech onsets (see `loud_burst_...` above, where a
+        // silence -> 0.8 transition produces flux in the hundreds) should
+        // treat this as background noise, not speech.
+        let mut vad = VadDetector::new(2.5, 50.0, 5);
+        let frame_a: Vec<f32> = (0..HOP_LEN)
+            .map(|i| if i % 2 == 0 { 0.0001 } else { -0.0001 })
+            .collect();
+        let frame_b: Vec<f32> = (0..HOP_LEN)
+            .map(|i| if i % 2 == 0 { -0.0001 } else { 0.0001 })
+            .collect();
+
+        for i in 0..30 {
+            let frame = if i % 2 == 0 { &frame_a } else { &frame_b };
+            assert_eq!(vad.push(frame), VadEvent::Silence);
+        }
+    }
+
+    #[test]
+    fn hangover_frames_for_ms_matches_hop_rate() {
+        assert_eq!(VadDetector::hangover_frames_for_ms(800), 80);
+    }
+}